@@ -1,5 +1,9 @@
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use std::collections::{HashMap, HashSet};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+use unicode_segmentation::UnicodeSegmentation;
 
 struct Matrix {
     width: i32,
@@ -32,15 +36,23 @@ impl Matrix {
 /// Derived from Wikipedia, the best possible source for an algorithm:
 /// https://en.wikipedia.org/w/index.php?title=Damerau%E2%80%93Levenshtein_distance&oldid=1050388400#Distance_with_adjacent_transpositions
 pub fn damerau_levenshtein_distance(a: &str, b: &str) -> i32 {
-    // Strings are 1-indexed, and d is -1-indexed.
     let a_chars: Vec<char> = a.chars().collect();
     let b_chars: Vec<char> = b.chars().collect();
 
+    damerau_levenshtein_core(&a_chars, &b_chars)
+}
+
+/// Shared Damerau-Levenshtein dynamic-programming core, generic over the
+/// unit being edited (`char`s for `damerau_levenshtein_distance`, grapheme
+/// cluster `&str`s for `damerau_levenshtein_distance_graphemes`), so the two
+/// callers can't drift out of sync the way a pasted copy would.
+fn damerau_levenshtein_core<T: Eq + Hash + Copy>(a: &[T], b: &[T]) -> i32 {
+    // Strings are 1-indexed, and d is -1-indexed.
     let a_len: i32 = a.len().try_into().unwrap();
     let b_len: i32 = b.len().try_into().unwrap();
 
-    let chars_set: HashSet<&char> = HashSet::from_iter(a_chars.iter().chain(b_chars.iter()));
-    let mut da: HashMap<char, i32> = HashMap::from_iter(chars_set.iter().map(|&&ch| (ch, 0)));
+    let unit_set: HashSet<&T> = HashSet::from_iter(a.iter().chain(b.iter()));
+    let mut da: HashMap<T, i32> = HashMap::from_iter(unit_set.iter().map(|&&unit| (unit, 0)));
 
     let mut matrix = Matrix::new(a_len + 2, b_len + 2);
 
@@ -60,9 +72,9 @@ pub fn damerau_levenshtein_distance(a: &str, b: &str) -> i32 {
     for i in 1..(a_len + 1) {
         let mut db = 0;
         for j in 1..(b_len + 1) {
-            let k = da[&b_chars[j as usize - 1]];
+            let k = da[&b[j as usize - 1]];
             let l = db;
-            let cost = if a_chars[i as usize - 1] == b_chars[j as usize - 1] {
+            let cost = if a[i as usize - 1] == b[j as usize - 1] {
                 db = j;
                 0
             } else {
@@ -71,7 +83,7 @@ pub fn damerau_levenshtein_distance(a: &str, b: &str) -> i32 {
             matrix.set(
                 i,
                 j,
-                *vec![
+                *[
                     matrix.get(i - 1, j - 1) + cost, // substitution
                     matrix.get(i, j - 1) + 1,        // insertion
                     matrix.get(i - 1, j) + 1,        // deletion
@@ -82,15 +94,682 @@ pub fn damerau_levenshtein_distance(a: &str, b: &str) -> i32 {
                 .unwrap_or(&0), // transposition
             );
         }
-        da.insert(a_chars[i as usize - 1], i);
+        da.insert(a[i as usize - 1], i);
     }
 
     matrix.get(a_len, b_len)
 }
 
+#[pyfunction]
+/// Banded variant of `damerau_levenshtein_distance` for callers who only
+/// care whether two strings are within `max_distance` of each other (for
+/// example, spell-checking a query against thousands of candidate ref
+/// targets). Only matrix cells within a band of `max_distance` around the
+/// diagonal are filled -- cells outside the band are treated as infinity --
+/// and the scan bails out as soon as a row's minimum value exceeds
+/// `max_distance`, returning `None`. The recurrence runs only on in-band
+/// cells, but the full (|a| + 2) x (|b| + 2) matrix is still allocated and
+/// every cell of each scanned row is visited, so the cost stays
+/// O(|a| * |b|); the early exit is what skips work on dissimilar strings.
+pub fn damerau_levenshtein_distance_bounded(a: &str, b: &str, max_distance: i32) -> Option<i32> {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    let a_len: i32 = a_chars.len().try_into().unwrap();
+    let b_len: i32 = b_chars.len().try_into().unwrap();
+    let k = max_distance;
+
+    if (a_len - b_len).abs() > k {
+        return None;
+    }
+
+    const INFINITY: i32 = i32::MAX / 2;
+
+    let chars_set: HashSet<&char> = HashSet::from_iter(a_chars.iter().chain(b_chars.iter()));
+    let mut da: HashMap<char, i32> = HashMap::from_iter(chars_set.iter().map(|&&ch| (ch, 0)));
+
+    let mut matrix = Matrix::new(a_len + 2, b_len + 2);
+
+    let maxdist = a_len + b_len;
+    matrix.set(-1, -1, maxdist);
+
+    for i in 0..(a_len + 1) {
+        matrix.set(i, -1, maxdist);
+        matrix.set(i, 0, if i <= k { i } else { INFINITY });
+    }
+
+    for j in 0..(b_len + 1) {
+        matrix.set(-1, j, maxdist);
+        matrix.set(0, j, if j <= k { j } else { INFINITY });
+    }
+
+    for i in 1..(a_len + 1) {
+        let mut db = 0;
+        let mut row_min = matrix.get(i, 0); // base case: column 0 (INFINITY once i > k)
+        let lo = (i - k).max(1);
+        let hi = (i + k).min(b_len);
+
+        for j in 1..(b_len + 1) {
+            if j < lo || j > hi {
+                matrix.set(i, j, INFINITY);
+                if a_chars[i as usize - 1] == b_chars[j as usize - 1] {
+                    db = j;
+                }
+                continue;
+            }
+
+            let k_row = da[&b_chars[j as usize - 1]];
+            let l = db;
+            let cost = if a_chars[i as usize - 1] == b_chars[j as usize - 1] {
+                db = j;
+                0
+            } else {
+                1
+            };
+            let value = *[
+                matrix.get(i - 1, j - 1) + cost, // substitution
+                matrix.get(i, j - 1) + 1,        // insertion
+                matrix.get(i - 1, j) + 1,        // deletion
+                matrix.get(k_row - 1, l - 1) + (i - k_row - 1) + 1 + (j - l - 1), // transposition
+            ]
+            .iter()
+            .min()
+            .unwrap();
+            matrix.set(i, j, value);
+            row_min = row_min.min(value);
+        }
+
+        da.insert(a_chars[i as usize - 1], i);
+
+        if row_min > k {
+            return None; // beyond threshold; no point finishing the matrix
+        }
+    }
+
+    let distance = matrix.get(a_len, b_len);
+    if distance > k {
+        None
+    } else {
+        Some(distance)
+    }
+}
+
+#[pyfunction]
+/// Like `damerau_levenshtein_distance`, but segments `a` and `b` into
+/// Unicode extended grapheme clusters instead of `char`s, so a combining
+/// sequence or an emoji-with-modifier counts as a single editable unit.
+/// This matters for MongoDB docs content, which is internationalized and can
+/// contain accented text, CJK, and emoji.
+pub fn damerau_levenshtein_distance_graphemes(a: &str, b: &str) -> i32 {
+    let a_graphemes: Vec<&str> = a.graphemes(true).collect();
+    let b_graphemes: Vec<&str> = b.graphemes(true).collect();
+
+    damerau_levenshtein_core(&a_graphemes, &b_graphemes)
+}
+
+#[pyfunction]
+/// Plain Levenshtein distance: the number of single-character insertions,
+/// deletions, and substitutions needed to turn `a` into `b`. Unlike
+/// `damerau_levenshtein_distance`, adjacent transpositions are not a single
+/// edit.
+///
+/// When the shorter of the two strings fits in a 64-bit word, this uses
+/// Myers' bit-parallel algorithm, which computes the distance in O(|a|*|b|
+/// / 64) rather than O(|a|*|b|) -- worthwhile when diffing long strings such
+/// as whole headings or captions. Longer patterns fall back to the plain
+/// dynamic-programming routine.
+pub fn levenshtein(a: &str, b: &str) -> i32 {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    let (pattern, text) = if a_chars.len() <= b_chars.len() {
+        (&a_chars, &b_chars)
+    } else {
+        (&b_chars, &a_chars)
+    };
+
+    if pattern.len() <= 64 {
+        myers_levenshtein(pattern, text)
+    } else {
+        levenshtein_dp(&a_chars, &b_chars)
+    }
+}
+
+/// Plain dynamic-programming Levenshtein distance, used as a fallback when
+/// the shorter string doesn't fit in a single machine word for
+/// `myers_levenshtein`.
+fn levenshtein_dp(a_chars: &[char], b_chars: &[char]) -> i32 {
+    let a_len = a_chars.len();
+    let b_len = b_chars.len();
+
+    let mut row: Vec<i32> = (0..=b_len as i32).collect();
+
+    for i in 1..=a_len {
+        let mut prev_diag = row[0];
+        row[0] = i as i32;
+        for j in 1..=b_len {
+            let temp = row[j];
+            let cost = if a_chars[i - 1] == b_chars[j - 1] { 0 } else { 1 };
+            row[j] = *[row[j] + 1, row[j - 1] + 1, prev_diag + cost]
+                .iter()
+                .min()
+                .unwrap();
+            prev_diag = temp;
+        }
+    }
+
+    row[b_len]
+}
+
+/// Myers' bit-vector Levenshtein distance between `pattern` and `text`,
+/// where `pattern.len()` must be at most 64 (the word width). Builds a
+/// per-character equality mask `peq[c]` with bit `j` set where
+/// `pattern[j] == c`, then slides it across `text` one character at a time,
+/// tracking positive (`Pv`) and negative (`Mv`) vertical deltas.
+fn myers_levenshtein(pattern: &[char], text: &[char]) -> i32 {
+    let m = pattern.len();
+    if m == 0 {
+        return text.len() as i32;
+    }
+
+    let mut peq: HashMap<char, u64> = HashMap::new();
+    for (j, &c) in pattern.iter().enumerate() {
+        *peq.entry(c).or_insert(0) |= 1 << j;
+    }
+
+    let last_bit = 1u64 << (m - 1);
+    let mut pv: u64 = !0;
+    let mut mv: u64 = 0;
+    let mut score = m as i32;
+
+    for &c in text {
+        let eq = *peq.get(&c).unwrap_or(&0);
+        let xv = eq | mv;
+        let xh = (((eq & pv).wrapping_add(pv)) ^ pv) | eq;
+        let mut ph = mv | !(xh | pv);
+        let mut mh = pv & xh;
+
+        if ph & last_bit != 0 {
+            score += 1;
+        }
+        if mh & last_bit != 0 {
+            score -= 1;
+        }
+
+        ph = (ph << 1) | 1;
+        mh <<= 1;
+        pv = mh | !(xv | ph);
+        mv = ph & xv;
+    }
+
+    score
+}
+
+#[pyfunction]
+/// Optimal string alignment (restricted edit) distance: like
+/// `damerau_levenshtein_distance`, but no substring may be edited more than
+/// once -- in particular, a transposed pair of characters may not be touched
+/// again afterwards. This is cheaper to compute than true Damerau-Levenshtein
+/// and is the more common "typo distance" used for fuzzy matching.
+pub fn optimal_string_alignment(a: &str, b: &str) -> i32 {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    let a_len = a_chars.len();
+    let b_len = b_chars.len();
+
+    let mut matrix = Matrix::new(a_len as i32 + 2, b_len as i32 + 2);
+
+    for i in 0..=a_len as i32 {
+        matrix.set(i, 0, i);
+    }
+    for j in 0..=b_len as i32 {
+        matrix.set(0, j, j);
+    }
+
+    for i in 1..=a_len as i32 {
+        for j in 1..=b_len as i32 {
+            let cost = if a_chars[i as usize - 1] == b_chars[j as usize - 1] {
+                0
+            } else {
+                1
+            };
+            let mut value = *[
+                matrix.get(i - 1, j) + 1,        // deletion
+                matrix.get(i, j - 1) + 1,        // insertion
+                matrix.get(i - 1, j - 1) + cost, // substitution
+            ]
+            .iter()
+            .min()
+            .unwrap();
+
+            if i > 1
+                && j > 1
+                && a_chars[i as usize - 1] == b_chars[j as usize - 2]
+                && a_chars[i as usize - 2] == b_chars[j as usize - 1]
+            {
+                value = value.min(matrix.get(i - 2, j - 2) + cost); // transposition
+            }
+
+            matrix.set(i, j, value);
+        }
+    }
+
+    matrix.get(a_len as i32, b_len as i32)
+}
+
+#[pyfunction]
+/// Hamming distance: the number of positions at which two equal-length
+/// strings differ. Raises a `ValueError` if the strings have different
+/// lengths, since the metric is undefined in that case.
+pub fn hamming(a: &str, b: &str) -> PyResult<i32> {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    if a_chars.len() != b_chars.len() {
+        return Err(PyValueError::new_err(
+            "hamming distance requires strings of equal length",
+        ));
+    }
+
+    Ok(a_chars
+        .iter()
+        .zip(b_chars.iter())
+        .filter(|(x, y)| x != y)
+        .count() as i32)
+}
+
+#[pyfunction]
+/// Jaro similarity, a measure in `[0.0, 1.0]` tuned for short strings such as
+/// names: characters are considered "matching" if they're equal and within
+/// `floor(max(|a|, |b|) / 2) - 1` positions of each other, and matches that
+/// appear in different relative order count as transpositions.
+pub fn jaro(a: &str, b: &str) -> f64 {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    jaro_similarity(&a_chars, &b_chars)
+}
+
+#[pyfunction]
+/// Jaro-Winkler similarity: the Jaro similarity, boosted by a bonus of
+/// `l * p * (1 - jaro)` for strings that share a common prefix, where `l` is
+/// the prefix length (capped at 4 characters) and `p` is a fixed scaling
+/// factor of 0.1. This favors matches that agree at the start of the string,
+/// which is common for typos in identifiers and names.
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    const PREFIX_WEIGHT: f64 = 0.1;
+    const MAX_PREFIX_LEN: usize = 4;
+
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    let jaro_sim = jaro_similarity(&a_chars, &b_chars);
+
+    let prefix_len = a_chars
+        .iter()
+        .zip(b_chars.iter())
+        .take(MAX_PREFIX_LEN)
+        .take_while(|(x, y)| x == y)
+        .count();
+
+    jaro_sim + (prefix_len as f64 * PREFIX_WEIGHT * (1.0 - jaro_sim))
+}
+
+/// Shared implementation of Jaro similarity over already-collected character
+/// vectors, so callers (`jaro` and `jaro_winkler`) don't each re-derive the
+/// match window and transposition count.
+fn jaro_similarity(a_chars: &[char], b_chars: &[char]) -> f64 {
+    let a_len = a_chars.len();
+    let b_len = b_chars.len();
+
+    if a_len == 0 || b_len == 0 {
+        return 0.0;
+    }
+
+    let match_distance = (a_len.max(b_len) / 2).saturating_sub(1);
+
+    let mut a_matches = vec![false; a_len];
+    let mut b_matches = vec![false; b_len];
+    let mut matches = 0;
+
+    for i in 0..a_len {
+        let lo = i.saturating_sub(match_distance);
+        let hi = (i + match_distance + 1).min(b_len);
+        for j in lo..hi {
+            if !b_matches[j] && a_chars[i] == b_chars[j] {
+                a_matches[i] = true;
+                b_matches[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let mut transpositions = 0;
+    let mut b_index = 0;
+    for i in 0..a_len {
+        if !a_matches[i] {
+            continue;
+        }
+        while !b_matches[b_index] {
+            b_index += 1;
+        }
+        if a_chars[i] != b_chars[b_index] {
+            transpositions += 1;
+        }
+        b_index += 1;
+    }
+    let transpositions = transpositions / 2;
+
+    let matches = matches as f64;
+    (matches / a_len as f64
+        + matches / b_len as f64
+        + (matches - transpositions as f64) / matches)
+        / 3.0
+}
+
+/// A single scored candidate, ordered by similarity (ties broken by earliest
+/// position in the input list) so it can live in both a bounded min-heap and
+/// a final descending sort.
+struct ScoredCandidate {
+    score: f64,
+    index: usize,
+    candidate: String,
+}
+
+impl PartialEq for ScoredCandidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.score == other.score && self.index == other.index
+    }
+}
+
+impl Eq for ScoredCandidate {}
+
+impl PartialOrd for ScoredCandidate {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScoredCandidate {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.score
+            .partial_cmp(&other.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (query, choices, score_cutoff=None, limit=5, scorer=None))]
+/// Find the `limit` candidates in `choices` most similar to `query`,
+/// sorted by descending normalized similarity. Distances are normalized to
+/// `[0.0, 1.0]` via `1.0 - distance / max(len_query, len_candidate)`, and any
+/// candidate scoring below `score_cutoff` is discarded.
+///
+/// `scorer` selects how similarity is computed: `"damerau_levenshtein"`
+/// (the default) suits general typos, while `"jaro_winkler"` weights
+/// matching prefixes more heavily, which works better for short identifiers.
+///
+/// Rather than scoring every candidate and sorting the full list, this keeps
+/// a bounded min-heap of size `limit`, so scoring `choices` costs
+/// O(choices * log(limit)) instead of O(choices * log(choices)).
+pub fn get_top_n(
+    query: &str,
+    choices: Vec<String>,
+    score_cutoff: Option<f64>,
+    limit: Option<usize>,
+    scorer: Option<&str>,
+) -> PyResult<Vec<(String, f64)>> {
+    let limit = limit.unwrap_or(5);
+    let cutoff = score_cutoff.unwrap_or(0.0);
+
+    let use_jaro_winkler = match scorer {
+        None | Some("damerau_levenshtein") => false,
+        Some("jaro_winkler") => true,
+        Some(other) => {
+            return Err(PyValueError::new_err(format!(
+                "unknown scorer {other:?}; expected \"damerau_levenshtein\" or \"jaro_winkler\""
+            )))
+        }
+    };
+
+    if limit == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut heap: BinaryHeap<Reverse<ScoredCandidate>> = BinaryHeap::with_capacity(limit);
+
+    for (index, candidate) in choices.into_iter().enumerate() {
+        let score = if use_jaro_winkler {
+            jaro_winkler(query, &candidate)
+        } else {
+            normalized_damerau_levenshtein(query, &candidate)
+        };
+
+        if score < cutoff {
+            continue;
+        }
+
+        let scored = ScoredCandidate {
+            score,
+            index,
+            candidate,
+        };
+
+        if heap.len() < limit {
+            heap.push(Reverse(scored));
+        } else if let Some(Reverse(smallest)) = heap.peek() {
+            if scored.score > smallest.score {
+                heap.pop();
+                heap.push(Reverse(scored));
+            }
+        }
+    }
+
+    let mut results: Vec<ScoredCandidate> = heap.into_iter().map(|Reverse(c)| c).collect();
+    results.sort_by(|a, b| b.cmp(a));
+
+    Ok(results
+        .into_iter()
+        .map(|c| (c.candidate, c.score))
+        .collect())
+}
+
+#[pyfunction]
+/// Normalized Damerau-Levenshtein similarity in `[0.0, 1.0]`, computed as
+/// `1.0 - distance / max(len_a, len_b)`. Unlike the raw distance, this is
+/// comparable across differently-sized strings, which makes it suitable for
+/// ranking matches. Two empty strings are defined as identical (`1.0`).
+pub fn normalized_damerau_levenshtein(a: &str, b: &str) -> f64 {
+    let max_len = a.chars().count().max(b.chars().count());
+    if max_len == 0 {
+        return 1.0;
+    }
+
+    let distance = damerau_levenshtein_distance(a, b) as f64;
+    1.0 - distance / max_len as f64
+}
+
+#[pyfunction]
+/// Sorensen-Dice coefficient over character bigrams, in `[0.0, 1.0]`: the
+/// multiset of adjacent character pairs is built for each string, and the
+/// score is `2 * shared / (bigrams_a + bigrams_b)`, where `shared` counts
+/// each repeated bigram at most as many times as it appears in both inputs.
+/// Unlike edit distance, this is robust to word reordering and token-level
+/// typos, which makes it a useful complement when matching multi-word docs
+/// titles and glossary terms.
+pub fn sorensen_dice(a: &str, b: &str) -> f64 {
+    let a_bigrams = char_bigrams(a);
+    let b_bigrams = char_bigrams(b);
+
+    let total = a_bigrams.len() + b_bigrams.len();
+    if total == 0 {
+        return 1.0;
+    }
+
+    let mut b_counts: HashMap<(char, char), i32> = HashMap::new();
+    for bigram in &b_bigrams {
+        *b_counts.entry(*bigram).or_insert(0) += 1;
+    }
+
+    let mut shared = 0;
+    for bigram in &a_bigrams {
+        if let Some(count) = b_counts.get_mut(bigram) {
+            if *count > 0 {
+                *count -= 1;
+                shared += 1;
+            }
+        }
+    }
+
+    (2 * shared) as f64 / total as f64
+}
+
+/// The multiset of adjacent character pairs in `s`, used by `sorensen_dice`.
+fn char_bigrams(s: &str) -> Vec<(char, char)> {
+    let chars: Vec<char> = s.chars().collect();
+    chars.windows(2).map(|w| (w[0], w[1])).collect()
+}
+
 /// A Python module implemented in Rust.
 #[pymodule]
 fn snooty_oxide(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(damerau_levenshtein_distance, m)?)?;
+    m.add_function(wrap_pyfunction!(damerau_levenshtein_distance_bounded, m)?)?;
+    m.add_function(wrap_pyfunction!(damerau_levenshtein_distance_graphemes, m)?)?;
+    m.add_function(wrap_pyfunction!(levenshtein, m)?)?;
+    m.add_function(wrap_pyfunction!(optimal_string_alignment, m)?)?;
+    m.add_function(wrap_pyfunction!(hamming, m)?)?;
+    m.add_function(wrap_pyfunction!(jaro, m)?)?;
+    m.add_function(wrap_pyfunction!(jaro_winkler, m)?)?;
+    m.add_function(wrap_pyfunction!(get_top_n, m)?)?;
+    m.add_function(wrap_pyfunction!(normalized_damerau_levenshtein, m)?)?;
+    m.add_function(wrap_pyfunction!(sorensen_dice, m)?)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn damerau_levenshtein_distance_known_vectors() {
+        assert_eq!(damerau_levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(damerau_levenshtein_distance("ca", "abc"), 2);
+        assert_eq!(damerau_levenshtein_distance("", ""), 0);
+        assert_eq!(damerau_levenshtein_distance("", "abc"), 3);
+        assert_eq!(damerau_levenshtein_distance("abc", ""), 3);
+    }
+
+    #[test]
+    fn damerau_levenshtein_distance_bounded_matches_unbounded_within_threshold() {
+        assert_eq!(damerau_levenshtein_distance_bounded("kitten", "sitting", 3), Some(3));
+        assert_eq!(damerau_levenshtein_distance_bounded("kitten", "sitting", 2), None);
+        assert_eq!(damerau_levenshtein_distance_bounded("", "", 0), Some(0));
+        // Base-case column/row must still be consulted when one string is empty.
+        assert_eq!(damerau_levenshtein_distance_bounded("abc", "", 10), Some(3));
+        assert_eq!(damerau_levenshtein_distance_bounded("", "abc", 10), Some(3));
+        assert_eq!(damerau_levenshtein_distance_bounded("abc", "", 2), None);
+    }
+
+    #[test]
+    fn damerau_levenshtein_distance_graphemes_counts_clusters_not_chars() {
+        // A family emoji built from a ZWJ sequence is one grapheme cluster but
+        // several `char`s, so the grapheme-aware distance should see it as a
+        // single edit where the char-aware distance sees several.
+        let a = "👨‍👩‍👧";
+        let b = "👨‍👩‍👧‍👦";
+        assert_eq!(damerau_levenshtein_distance_graphemes(a, a), 0);
+        assert!(damerau_levenshtein_distance_graphemes(a, b) < damerau_levenshtein_distance(a, b));
+    }
+
+    #[test]
+    fn levenshtein_known_vectors() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", ""), 0);
+        assert_eq!(levenshtein("", "abc"), 3);
+        // Adjacent transpositions cost two edits here, unlike Damerau-Levenshtein.
+        assert_eq!(levenshtein("ab", "ba"), 2);
+    }
+
+    #[test]
+    fn levenshtein_falls_back_to_dp_beyond_word_width() {
+        let a = "a".repeat(100);
+        let mut b = a.clone();
+        b.replace_range(50..51, "b");
+        assert_eq!(levenshtein(&a, &b), 1);
+
+        let c = "x".repeat(65);
+        let d = "y".repeat(65);
+        assert_eq!(levenshtein(&c, &d), 65);
+    }
+
+    #[test]
+    fn optimal_string_alignment_known_vectors() {
+        assert_eq!(optimal_string_alignment("", ""), 0);
+        assert_eq!(optimal_string_alignment("ab", "ab"), 0);
+        assert_eq!(optimal_string_alignment("kitten", "sitting"), 3);
+        // A single adjacent transposition is one edit.
+        assert_eq!(optimal_string_alignment("ab", "ba"), 1);
+    }
+
+    #[test]
+    fn hamming_known_vectors() {
+        assert_eq!(hamming("karolin", "kathrin").unwrap(), 3);
+        assert_eq!(hamming("", "").unwrap(), 0);
+        assert!(hamming("ab", "abc").is_err());
+    }
+
+    #[test]
+    fn jaro_known_vectors() {
+        // Classic reference pair: https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance
+        assert!((jaro("MARTHA", "MARHTA") - 0.944).abs() < 0.001);
+        assert_eq!(jaro("", ""), 0.0);
+        assert_eq!(jaro("abc", "abc"), 1.0);
+    }
+
+    #[test]
+    fn jaro_winkler_boosts_common_prefix() {
+        let jaro_sim = jaro("MARTHA", "MARHTA");
+        let jaro_winkler_sim = jaro_winkler("MARTHA", "MARHTA");
+        assert!(jaro_winkler_sim > jaro_sim);
+        assert!((jaro_winkler_sim - 0.961).abs() < 0.001);
+    }
+
+    #[test]
+    fn normalized_damerau_levenshtein_known_vectors() {
+        assert_eq!(normalized_damerau_levenshtein("", ""), 1.0);
+        assert_eq!(normalized_damerau_levenshtein("abc", "abc"), 1.0);
+        assert!((normalized_damerau_levenshtein("abc", "abd") - (2.0 / 3.0)).abs() < 0.001);
+    }
+
+    #[test]
+    fn sorensen_dice_known_vectors() {
+        assert_eq!(sorensen_dice("", ""), 1.0);
+        assert_eq!(sorensen_dice("night", "night"), 1.0);
+        // Bigrams are character pairs: "ni","ig","gh","ht" vs
+        // "na","ac","ch","ht" -> one shared ("ht").
+        assert!((sorensen_dice("night", "nacht") - (2.0 * 1.0 / 8.0)).abs() < 0.001);
+    }
+
+    #[test]
+    fn get_top_n_ranks_closest_matches_first() {
+        let results =
+            get_top_n("refrence", vec!["reference".to_string(), "unrelated".to_string()], None, Some(2), None)
+                .unwrap();
+        assert_eq!(results[0].0, "reference");
+
+        // An unreachable cutoff should filter every candidate out.
+        let none =
+            get_top_n("refrence", vec!["reference".to_string()], Some(0.99), Some(2), None).unwrap();
+        assert!(none.is_empty());
+
+        assert!(get_top_n("a", vec![], None, Some(2), Some("bogus")).is_err());
+    }
+}